@@ -0,0 +1,137 @@
+//! Cache cleaning functionality
+//! Migrated from original eframe/egui main.rs
+
+use crate::commands::progress::{self, ProgressLevel};
+use crate::commands::scanner::get_capcut_root_path;
+use crate::commands::transaction::Transaction;
+use std::fs;
+use std::path::PathBuf;
+use tauri::AppHandle;
+use walkdir::WalkDir;
+
+/// Cache directories cleared out as part of a "full protection" pass
+const CACHE_DIRS: &[&str] = &["User Data/Cache", "User Data/GPUCache", "User Data/Code Cache"];
+
+fn cache_paths() -> Vec<PathBuf> {
+    match get_capcut_root_path() {
+        Some(root) => CACHE_DIRS.iter().map(|d| root.join(d)).collect(),
+        None => Vec::new(),
+    }
+}
+
+fn dir_size(path: &PathBuf) -> u64 {
+    WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter_map(|e| e.metadata().ok())
+        .filter(|m| m.is_file())
+        .map(|m| m.len())
+        .sum()
+}
+
+/// Calculate the total size of CapCut's cache directories, in bytes
+#[tauri::command]
+pub fn calculate_cache_size() -> u64 {
+    cache_paths().iter().filter(|p| p.exists()).map(dir_size).sum()
+}
+
+/// Result of a cache clean
+#[derive(serde::Serialize)]
+pub struct CleanResult {
+    pub success: bool,
+    pub logs: Vec<String>,
+}
+
+/// Remove CapCut's cache directories, reporting live progress to `app`.
+/// When `dry_run` is set, only the size each directory would free is
+/// computed and logged with a `[DRY RUN]` prefix — nothing is deleted.
+#[tauri::command]
+pub fn clean_cache(app: AppHandle, dry_run: bool) -> CleanResult {
+    let mut logs: Vec<String> = Vec::new();
+    let paths = cache_paths();
+
+    for (i, path) in paths.iter().enumerate() {
+        if !path.exists() {
+            continue;
+        }
+        let name = path.to_string_lossy().to_string();
+        let percent = (((i + 1) * 100) / paths.len().max(1)) as u8;
+
+        if dry_run {
+            let message = format!("[DRY RUN] Would clear: {} ({} bytes)", name, dir_size(path));
+            progress::emit(&app, "clean_cache", &message, ProgressLevel::Info, percent);
+            logs.push(message);
+            continue;
+        }
+
+        match fs::remove_dir_all(path) {
+            Ok(_) => {
+                let message = format!("[OK] Cleared: {}", name);
+                progress::emit(&app, "clean_cache", &message, ProgressLevel::Info, percent);
+                logs.push(message);
+            }
+            Err(e) => {
+                let message = format!("[!] Failed to clear {}: {}", name, e);
+                progress::emit(&app, "clean_cache", &message, ProgressLevel::Warn, percent);
+                logs.push(message);
+            }
+        }
+    }
+
+    if logs.is_empty() {
+        let message = if dry_run {
+            "[DRY RUN] No cache to clean"
+        } else {
+            "[OK] No cache to clean"
+        };
+        logs.push(message.to_string());
+    }
+
+    CleanResult {
+        success: true,
+        logs,
+    }
+}
+
+/// Quarantine the cache directories into `txn` instead of deleting them
+/// outright, so `run_full_protection` can roll the clean back along with the
+/// rest of the sequence if a later step fails. A directory that fails to
+/// quarantine aborts the whole sequence, same as `quarantine_versions` does,
+/// so `run_full_protection` can't report overall success while a cache
+/// directory was silently left in place.
+pub(crate) fn quarantine_cache(
+    app: &AppHandle,
+    txn: &mut Transaction,
+    percent_start: u8,
+    percent_end: u8,
+) -> Result<Vec<String>, (String, Vec<String>)> {
+    let mut logs: Vec<String> = Vec::new();
+    let paths: Vec<PathBuf> = cache_paths().into_iter().filter(|p| p.exists()).collect();
+
+    for (i, path) in paths.iter().enumerate() {
+        let name = path.to_string_lossy().to_string();
+        let percent = percent_start
+            + ((percent_end - percent_start) as usize * (i + 1) / paths.len().max(1)) as u8;
+
+        match txn.quarantine(path) {
+            Ok(_) => {
+                let message = format!("[OK] Cleared: {}", name);
+                progress::emit(app, "clean_cache", &message, ProgressLevel::Info, percent);
+                logs.push(message);
+            }
+            Err(e) => {
+                let error = format!("Failed to clear {}: {}", name, e);
+                progress::emit(app, "clean_cache", &error, ProgressLevel::Error, percent);
+                return Err((error, logs));
+            }
+        }
+    }
+
+    if logs.is_empty() {
+        let message = "[OK] No cache to clean".to_string();
+        progress::emit(app, "clean_cache", &message, ProgressLevel::Info, percent_end);
+        logs.push(message);
+    }
+
+    Ok(logs)
+}
@@ -0,0 +1,243 @@
+//! Persistent lock manifest recorded by protection, so later checks can
+//! detect tampering instead of re-deriving protection state heuristically
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Manifest file name, written under the CapCut root directory
+pub const MANIFEST_FILE: &str = "VersionGuard.lock";
+
+/// A single file recorded by the manifest, along with its hash and readonly
+/// attribute at lock time. Blockers are expected to stay readonly, so an
+/// updater clearing that attribute ahead of overwriting the content is
+/// itself a tamper signal, even before the bytes change.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ManifestEntry {
+    pub path: String,
+    pub sha256: String,
+    pub readonly: bool,
+}
+
+impl ManifestEntry {
+    fn for_path(path: &Path) -> Result<ManifestEntry, String> {
+        Ok(ManifestEntry {
+            path: path.to_string_lossy().to_string(),
+            sha256: hash_file(path)?,
+            readonly: is_readonly(path),
+        })
+    }
+}
+
+fn is_readonly(path: &Path) -> bool {
+    fs::metadata(path)
+        .map(|m| m.permissions().readonly())
+        .unwrap_or(false)
+}
+
+fn hash_file(path: &Path) -> Result<String, String> {
+    let bytes = fs::read(path).map_err(|e| e.to_string())?;
+    let mut hasher = Sha256::new();
+    hasher.update(&bytes);
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// The protection state recorded by `apply_protection`/`run_full_protection`
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Manifest {
+    pub protected_version: String,
+    pub blockers: Vec<ManifestEntry>,
+    pub config: Option<ManifestEntry>,
+    pub locked_at: u64,
+}
+
+impl Manifest {
+    /// Build a manifest from the blockers and config file as they exist
+    /// right now, at lock time
+    pub fn build(
+        protected_version: &str,
+        blocker_paths: &[PathBuf],
+        config_path: &Path,
+    ) -> Result<Manifest, String> {
+        let blockers = blocker_paths
+            .iter()
+            .map(|p| ManifestEntry::for_path(p))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let config = if config_path.exists() {
+            Some(ManifestEntry::for_path(config_path)?)
+        } else {
+            None
+        };
+
+        let locked_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| e.to_string())?
+            .as_secs();
+
+        Ok(Manifest {
+            protected_version: protected_version.to_string(),
+            blockers,
+            config,
+            locked_at,
+        })
+    }
+
+    pub fn path(capcut_root: &Path) -> PathBuf {
+        capcut_root.join(MANIFEST_FILE)
+    }
+
+    pub fn save(&self, capcut_root: &Path) -> Result<(), String> {
+        let content = toml::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(Self::path(capcut_root), content).map_err(|e| e.to_string())
+    }
+
+    pub fn load(capcut_root: &Path) -> Option<Manifest> {
+        let content = fs::read_to_string(Self::path(capcut_root)).ok()?;
+        toml::from_str(&content).ok()
+    }
+
+    pub fn remove(capcut_root: &Path) -> Result<(), String> {
+        let path = Self::path(capcut_root);
+        if path.exists() {
+            fs::remove_file(path).map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+}
+
+/// Per-entry result of re-hashing a recorded file against the manifest
+#[derive(Serialize)]
+pub struct IntegrityVerdict {
+    pub path: String,
+    pub exists: bool,
+    pub tampered: bool,
+}
+
+impl ManifestEntry {
+    /// Re-hash the file this entry points at and compare against the
+    /// recorded hash, and re-check its readonly attribute against the
+    /// recorded one. A missing file, a hash mismatch, or a readonly
+    /// attribute that was cleared all count as tampered.
+    pub fn verify(&self) -> IntegrityVerdict {
+        let path = PathBuf::from(&self.path);
+
+        if !path.exists() {
+            return IntegrityVerdict {
+                path: self.path.clone(),
+                exists: false,
+                tampered: true,
+            };
+        }
+
+        let hash_tampered = match hash_file(&path) {
+            Ok(current_hash) => current_hash != self.sha256,
+            Err(_) => true,
+        };
+        let readonly_tampered = self.readonly && !is_readonly(&path);
+
+        IntegrityVerdict {
+            path: self.path.clone(),
+            exists: true,
+            tampered: hash_tampered || readonly_tampered,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// A fresh scratch directory under the OS temp dir, unique per test
+    fn scratch_dir(name: &str) -> PathBuf {
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let id = COUNTER.fetch_add(1, Ordering::SeqCst);
+        let dir = std::env::temp_dir().join(format!(
+            "ccvg_test_{}_{}_{}",
+            name,
+            std::process::id(),
+            id
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn readonly_file(path: &Path, content: &str) {
+        fs::write(path, content).unwrap();
+        let mut perms = fs::metadata(path).unwrap().permissions();
+        perms.set_readonly(true);
+        fs::set_permissions(path, perms).unwrap();
+    }
+
+    #[test]
+    fn verify_passes_for_an_untouched_entry() {
+        let dir = scratch_dir("manifest_untouched");
+        let path = dir.join("blocker.txt");
+        readonly_file(&path, "blocked");
+
+        let entry = ManifestEntry::for_path(&path).unwrap();
+        assert!(!entry.verify().tampered);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn verify_flags_a_missing_file_as_tampered() {
+        let dir = scratch_dir("manifest_missing");
+        let path = dir.join("blocker.txt");
+        readonly_file(&path, "blocked");
+
+        let entry = ManifestEntry::for_path(&path).unwrap();
+        let mut perms = fs::metadata(&path).unwrap().permissions();
+        perms.set_readonly(false);
+        fs::set_permissions(&path, perms).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let verdict = entry.verify();
+        assert!(!verdict.exists);
+        assert!(verdict.tampered);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn verify_flags_changed_bytes_as_tampered() {
+        let dir = scratch_dir("manifest_changed_bytes");
+        let path = dir.join("blocker.txt");
+        readonly_file(&path, "blocked");
+
+        let entry = ManifestEntry::for_path(&path).unwrap();
+
+        let mut perms = fs::metadata(&path).unwrap().permissions();
+        perms.set_readonly(false);
+        fs::set_permissions(&path, perms).unwrap();
+        fs::write(&path, "tampered").unwrap();
+
+        assert!(entry.verify().tampered);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn verify_flags_cleared_readonly_as_tampered() {
+        let dir = scratch_dir("manifest_cleared_readonly");
+        let path = dir.join("blocker.txt");
+        readonly_file(&path, "blocked");
+
+        let entry = ManifestEntry::for_path(&path).unwrap();
+        assert!(entry.readonly);
+
+        // Same bytes, but the readonly attribute was cleared ahead of an
+        // updater overwrite — that alone should count as tampered
+        let mut perms = fs::metadata(&path).unwrap().permissions();
+        perms.set_readonly(false);
+        fs::set_permissions(&path, perms).unwrap();
+
+        assert!(entry.verify().tampered);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
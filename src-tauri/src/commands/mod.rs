@@ -0,0 +1,10 @@
+pub mod cleaner;
+pub mod manifest;
+pub mod process;
+pub mod progress;
+pub mod protector;
+pub mod scanner;
+pub mod switcher;
+pub mod transaction;
+pub mod version;
+pub mod watcher;
@@ -0,0 +1,46 @@
+//! Structured progress events emitted by long-running protection commands
+//!
+//! Complements the `logs: Vec<String>` every command still returns: the
+//! frontend can listen on `ccvg://progress` for a live console and progress
+//! bar instead of waiting for the whole command to resolve.
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+
+/// Event name the frontend subscribes to for live progress
+pub const PROGRESS_EVENT: &str = "ccvg://progress";
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProgressLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+#[derive(Clone, Serialize)]
+pub struct ProgressEvent {
+    pub step: String,
+    pub message: String,
+    pub level: ProgressLevel,
+    pub percent: u8,
+}
+
+/// Emit a progress event to the frontend and mirror it into the `log` crate
+pub fn emit(app: &AppHandle, step: &str, message: &str, level: ProgressLevel, percent: u8) {
+    match level {
+        ProgressLevel::Info => log::info!("[{}] {}", step, message),
+        ProgressLevel::Warn => log::warn!("[{}] {}", step, message),
+        ProgressLevel::Error => log::error!("[{}] {}", step, message),
+    }
+
+    let _ = app.emit(
+        PROGRESS_EVENT,
+        ProgressEvent {
+            step: step.to_string(),
+            message: message.to_string(),
+            level,
+            percent,
+        },
+    );
+}
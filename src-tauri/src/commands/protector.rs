@@ -1,9 +1,14 @@
 //! Protection and file locking functionality
 //! Migrated from original eframe/egui main.rs
 
+use crate::commands::manifest;
+use crate::commands::progress::{self, ProgressLevel};
+use crate::commands::scanner;
+use crate::commands::transaction::Transaction;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use tauri::AppHandle;
 use walkdir::WalkDir;
 
 /// Unset readonly attribute recursively
@@ -40,6 +45,21 @@ fn create_readonly(path: &Path) -> Result<(), String> {
     Ok(())
 }
 
+/// Re-create a single blocker file at `path`, used by the watcher to repair
+/// a blocker CapCut's updater removed or un-readonly'd
+pub(crate) fn reapply_blocker(path: &Path) -> Result<(), String> {
+    create_readonly(path)
+}
+
+/// Re-lock `configure.ini` under `apps_path`, used by the watcher to repair
+/// a configuration CapCut's updater rewrote
+pub(crate) fn reapply_config_lock(apps_path: &Path) -> Result<(), String> {
+    lock_configuration(apps_path)
+}
+
+/// The `last_version` written into `configure.ini` to pin CapCut in place
+const LOCKED_VERSION: &str = "1.0.0.0";
+
 /// Lock configuration file
 fn lock_configuration(apps_path: &Path) -> Result<(), String> {
     let config_path = apps_path.join("configure.ini");
@@ -54,7 +74,7 @@ fn lock_configuration(apps_path: &Path) -> Result<(), String> {
 
     for line in content.lines() {
         if line.trim().starts_with("last_version") {
-            new_lines.push("last_version=1.0.0.0".to_string());
+            new_lines.push(format!("last_version={}", LOCKED_VERSION));
             found = true;
         } else {
             new_lines.push(line.to_string());
@@ -62,15 +82,16 @@ fn lock_configuration(apps_path: &Path) -> Result<(), String> {
     }
 
     if !found {
-        new_lines.push("last_version=1.0.0.0".to_string());
+        new_lines.push(format!("last_version={}", LOCKED_VERSION));
     }
 
     fs::write(config_path, new_lines.join("\n")).map_err(|e| e.to_string())?;
     Ok(())
 }
 
-/// Create dummy blocker files
-fn create_dummy_files(capcut_path: &Path, apps_path: &Path) -> Result<(), String> {
+/// Create dummy blocker files, returning the paths of the blockers created so
+/// they can be recorded in the lock manifest
+fn create_dummy_files(capcut_path: &Path, apps_path: &Path) -> Result<Vec<PathBuf>, String> {
     let pinfo = apps_path.join("ProductInfo.xml");
     create_readonly(&pinfo)?;
 
@@ -80,7 +101,7 @@ fn create_dummy_files(capcut_path: &Path, apps_path: &Path) -> Result<(), String
     let update_exe = download_dir.join("update.exe");
     create_readonly(&update_exe)?;
 
-    Ok(())
+    Ok(vec![pinfo, update_exe])
 }
 
 /// Protection result
@@ -91,45 +112,136 @@ pub struct ProtectionResult {
     pub logs: Vec<String>,
 }
 
-/// Delete specified version directories
-#[tauri::command]
-pub fn delete_versions(paths: Vec<String>) -> ProtectionResult {
+fn dir_size(path: &Path) -> u64 {
+    WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter_map(|e| e.metadata().ok())
+        .filter(|m| m.is_file())
+        .map(|m| m.len())
+        .sum()
+}
+
+/// Preview what `quarantine_versions` would do, without touching the
+/// filesystem
+fn preview_delete_versions(app: &AppHandle, paths: &[String]) -> Vec<String> {
     let mut logs: Vec<String> = Vec::new();
 
-    for path_str in &paths {
+    for path_str in paths {
         let path = PathBuf::from(path_str);
-        let name = path.file_name().unwrap_or_default().to_string_lossy();
-        logs.push(format!("Deleting: {}", name));
+        let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+        let message = format!("[DRY RUN] Would delete: {} ({} bytes)", name, dir_size(&path));
+        progress::emit(app, "delete_versions", &message, ProgressLevel::Info, 0);
+        logs.push(message);
+    }
+
+    let summary = if paths.is_empty() {
+        "[DRY RUN] No versions would be deleted".to_string()
+    } else {
+        format!("[DRY RUN] Would delete {} version(s)", paths.len())
+    };
+    progress::emit(app, "delete_versions", &summary, ProgressLevel::Info, 40);
+    logs.push(summary);
+
+    logs
+}
+
+/// Quarantine each of `paths` into `txn` instead of deleting it directly, so
+/// the caller can roll back if a later step in the sequence fails. Emits a
+/// progress event per version, scaled to `[percent_start, percent_end]`.
+fn quarantine_versions(
+    app: &AppHandle,
+    txn: &mut Transaction,
+    paths: &[String],
+    percent_start: u8,
+    percent_end: u8,
+) -> Result<Vec<String>, (String, Vec<String>)> {
+    let mut logs: Vec<String> = Vec::new();
+
+    for (i, path_str) in paths.iter().enumerate() {
+        let path = PathBuf::from(path_str);
+        let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+        let percent = percent_start
+            + ((percent_end - percent_start) as usize * (i + 1) / paths.len().max(1)) as u8;
+
+        let message = format!("Deleting: {}", name);
+        progress::emit(app, "delete_versions", &message, ProgressLevel::Info, percent);
+        logs.push(message);
 
         if let Err(e) = unset_readonly_recursive(&path) {
-            logs.push(format!("[!] Warning: {}", e));
+            let warning = format!("[!] Warning: {}", e);
+            progress::emit(app, "delete_versions", &warning, ProgressLevel::Warn, percent);
+            logs.push(warning);
         }
 
-        if let Err(e) = fs::remove_dir_all(&path) {
-            return ProtectionResult {
-                success: false,
-                error: Some(format!("Failed to delete {}: {}", name, e)),
-                logs,
-            };
+        if let Err(e) = txn.quarantine(&path) {
+            let error = format!("Failed to delete {}: {}", name, e);
+            progress::emit(app, "delete_versions", &error, ProgressLevel::Error, percent);
+            return Err((error, logs));
         }
     }
 
-    if paths.is_empty() {
-        logs.push("[OK] No versions to delete".to_string());
+    let summary = if paths.is_empty() {
+        "[OK] No versions to delete".to_string()
     } else {
-        logs.push(format!("[OK] Deleted {} version(s)", paths.len()));
-    }
+        format!("[OK] Deleted {} version(s)", paths.len())
+    };
+    progress::emit(app, "delete_versions", &summary, ProgressLevel::Info, percent_end);
+    logs.push(summary);
 
-    ProtectionResult {
-        success: true,
-        error: None,
-        logs,
+    Ok(logs)
+}
+
+/// Delete specified version directories
+///
+/// Each directory is quarantined rather than removed outright, then the
+/// quarantine is committed immediately since this command is a complete
+/// operation in its own right (see `run_full_protection` for the case where
+/// the quarantine spans multiple steps and can be rolled back as a whole).
+#[tauri::command]
+pub fn delete_versions(app: AppHandle, paths: Vec<String>) -> ProtectionResult {
+    let capcut_root = match scanner::get_capcut_root_path() {
+        Some(p) => p,
+        None => {
+            return ProtectionResult {
+                success: false,
+                error: Some("Failed to get LOCALAPPDATA".to_string()),
+                logs: vec![],
+            }
+        }
+    };
+
+    let mut txn = match Transaction::new(&capcut_root) {
+        Ok(t) => t,
+        Err(e) => {
+            return ProtectionResult {
+                success: false,
+                error: Some(e),
+                logs: vec![],
+            }
+        }
+    };
+
+    match quarantine_versions(&app, &mut txn, &paths, 0, 100) {
+        Ok(logs) => {
+            txn.commit();
+            ProtectionResult {
+                success: true,
+                error: None,
+                logs,
+            }
+        }
+        Err((error, logs)) => ProtectionResult {
+            success: false,
+            error: Some(error),
+            logs,
+        },
     }
 }
 
 /// Apply protection (lock config + create blockers)
 #[tauri::command]
-pub fn apply_protection() -> ProtectionResult {
+pub fn apply_protection(app: AppHandle) -> ProtectionResult {
     let apps_path = match std::env::var("LOCALAPPDATA") {
         Ok(p) => PathBuf::from(p).join("CapCut").join("Apps"),
         Err(_) => {
@@ -145,27 +257,40 @@ pub fn apply_protection() -> ProtectionResult {
     let mut logs: Vec<String> = Vec::new();
 
     // Lock configuration
+    progress::emit(&app, "lock_config", "Modifying config...", ProgressLevel::Info, 25);
     logs.push("Modifying config...".to_string());
     if let Err(e) = lock_configuration(&apps_path) {
+        progress::emit(&app, "lock_config", &e, ProgressLevel::Error, 25);
         return ProtectionResult {
             success: false,
             error: Some(e),
             logs,
         };
     }
+    progress::emit(&app, "lock_config", "[OK] Configuration locked", ProgressLevel::Info, 50);
     logs.push("[OK] Configuration locked".to_string());
 
     // Create blockers
+    progress::emit(&app, "create_blockers", "Creating blockers...", ProgressLevel::Info, 75);
     logs.push("Creating blockers...".to_string());
-    if let Err(e) = create_dummy_files(&capcut_root, &apps_path) {
-        return ProtectionResult {
-            success: false,
-            error: Some(e),
-            logs,
-        };
-    }
+    let blocker_paths = match create_dummy_files(&capcut_root, &apps_path) {
+        Ok(paths) => paths,
+        Err(e) => {
+            progress::emit(&app, "create_blockers", &e, ProgressLevel::Error, 75);
+            return ProtectionResult {
+                success: false,
+                error: Some(e),
+                logs,
+            }
+        }
+    };
+    progress::emit(&app, "create_blockers", "[OK] Update blockers created", ProgressLevel::Info, 100);
     logs.push("[OK] Update blockers created".to_string());
 
+    if let Err(e) = save_manifest(&capcut_root, &apps_path, &blocker_paths) {
+        logs.push(format!("[!] Warning: failed to write lock manifest: {}", e));
+    }
+
     ProtectionResult {
         success: true,
         error: None,
@@ -173,8 +298,41 @@ pub fn apply_protection() -> ProtectionResult {
     }
 }
 
-/// Apply protection with specific options
-pub fn apply_protection_with_options(lock_config: bool, create_blockers: bool) -> ProtectionResult {
+/// Build and save the lock manifest recording what protection was applied.
+///
+/// `blocker_paths` only covers blockers (re)created in this call — when
+/// `create_blockers` was disabled it's empty even though earlier blockers
+/// are still sitting on disk and protecting. In that case fall back to
+/// whatever the existing manifest already recorded, so neither
+/// `check_protection_status` nor the watcher lose track of them.
+fn save_manifest(
+    capcut_root: &Path,
+    apps_path: &Path,
+    blocker_paths: &[PathBuf],
+) -> Result<(), String> {
+    let config_path = apps_path.join("configure.ini");
+
+    let merged_blockers: Vec<PathBuf> = if blocker_paths.is_empty() {
+        manifest::Manifest::load(capcut_root)
+            .map(|m| m.blockers.into_iter().map(|e| PathBuf::from(e.path)).collect())
+            .unwrap_or_default()
+    } else {
+        blocker_paths.to_vec()
+    };
+
+    let manifest = manifest::Manifest::build(LOCKED_VERSION, &merged_blockers, &config_path)?;
+    manifest.save(capcut_root)
+}
+
+/// Apply protection with specific options. When `dry_run` is set, no file
+/// is touched: the log reports what configure.ini line and blocker files
+/// would be written, each prefixed `[DRY RUN]`.
+pub fn apply_protection_with_options(
+    app: &AppHandle,
+    lock_config: bool,
+    create_blockers: bool,
+    dry_run: bool,
+) -> ProtectionResult {
     let apps_path = match std::env::var("LOCALAPPDATA") {
         Ok(p) => PathBuf::from(p).join("CapCut").join("Apps"),
         Err(_) => {
@@ -191,32 +349,66 @@ pub fn apply_protection_with_options(lock_config: bool, create_blockers: bool) -
 
     // Lock configuration if enabled
     if lock_config {
-        logs.push("Modifying config...".to_string());
-        if let Err(e) = lock_configuration(&apps_path) {
-            return ProtectionResult {
-                success: false,
-                error: Some(e),
-                logs,
-            };
+        if dry_run {
+            let message = format!("[DRY RUN] Would set last_version={}", LOCKED_VERSION);
+            progress::emit(app, "lock_config", &message, ProgressLevel::Info, 50);
+            logs.push(message);
+        } else {
+            progress::emit(app, "lock_config", "Modifying config...", ProgressLevel::Info, 50);
+            logs.push("Modifying config...".to_string());
+            if let Err(e) = lock_configuration(&apps_path) {
+                progress::emit(app, "lock_config", &e, ProgressLevel::Error, 50);
+                return ProtectionResult {
+                    success: false,
+                    error: Some(e),
+                    logs,
+                };
+            }
+            progress::emit(app, "lock_config", "[OK] Configuration locked", ProgressLevel::Info, 50);
+            logs.push("[OK] Configuration locked".to_string());
         }
-        logs.push("[OK] Configuration locked".to_string());
     } else {
         logs.push("Skipping config lock (disabled)".to_string());
     }
 
     // Create blockers if enabled
-    if create_blockers {
-        logs.push("Creating blockers...".to_string());
-        if let Err(e) = create_dummy_files(&capcut_root, &apps_path) {
-            return ProtectionResult {
-                success: false,
-                error: Some(e),
-                logs,
+    let blocker_paths = if create_blockers {
+        if dry_run {
+            let pinfo = apps_path.join("ProductInfo.xml");
+            let update_exe = capcut_root.join("User Data").join("Download").join("update.exe");
+            for path in [&pinfo, &update_exe] {
+                let message = format!("[DRY RUN] Would create blocker: {}", path.to_string_lossy());
+                progress::emit(app, "create_blockers", &message, ProgressLevel::Info, 100);
+                logs.push(message);
+            }
+            vec![pinfo, update_exe]
+        } else {
+            progress::emit(app, "create_blockers", "Creating blockers...", ProgressLevel::Info, 75);
+            logs.push("Creating blockers...".to_string());
+            let paths = match create_dummy_files(&capcut_root, &apps_path) {
+                Ok(paths) => paths,
+                Err(e) => {
+                    progress::emit(app, "create_blockers", &e, ProgressLevel::Error, 75);
+                    return ProtectionResult {
+                        success: false,
+                        error: Some(e),
+                        logs,
+                    }
+                }
             };
+            progress::emit(app, "create_blockers", "[OK] Update blockers created", ProgressLevel::Info, 100);
+            logs.push("[OK] Update blockers created".to_string());
+            paths
         }
-        logs.push("[OK] Update blockers created".to_string());
     } else {
         logs.push("Skipping blocker creation (disabled)".to_string());
+        Vec::new()
+    };
+
+    if !dry_run && (lock_config || create_blockers) {
+        if let Err(e) = save_manifest(&capcut_root, &apps_path, &blocker_paths) {
+            logs.push(format!("[!] Warning: failed to write lock manifest: {}", e));
+        }
     }
 
     ProtectionResult {
@@ -233,49 +425,141 @@ pub struct ProtectionParams {
     pub clean_cache: bool,
     pub lock_config: bool,
     pub create_blockers: bool,
+    /// Preview the full sequence without touching the filesystem: every log
+    /// line is prefixed `[DRY RUN]` and nothing is deleted, cleaned, locked,
+    /// or written.
+    pub dry_run: bool,
 }
 
+/// Run the full delete + clean + protect sequence atomically: a single
+/// `Transaction` spans the version deletes and the cache clean, so if any
+/// step after either of those fails, dropping the transaction on the early
+/// return restores every quarantined version directory and cache folder.
+/// Only a fully successful run commits the quarantine.
 #[tauri::command]
-pub fn run_full_protection(params: ProtectionParams) -> ProtectionResult {
+pub fn run_full_protection(app: AppHandle, params: ProtectionParams) -> ProtectionResult {
     use crate::commands::cleaner;
     use crate::commands::process;
 
     let mut all_logs: Vec<String> = Vec::new();
 
+    // A dry run previews the plan without touching the filesystem, so it
+    // must not be gated on CapCut being closed — that check only matters
+    // for the real, destructive sequence below.
+    if params.dry_run {
+        all_logs.extend(preview_delete_versions(&app, &params.versions_to_delete));
+
+        if params.clean_cache {
+            all_logs.push("[DRY RUN] Previewing cache clean...".to_string());
+            let cache_result = cleaner::clean_cache(app.clone(), true);
+            all_logs.extend(cache_result.logs);
+        } else {
+            all_logs.push("[DRY RUN] Skipping cache cleaning (disabled)".to_string());
+        }
+
+        if params.lock_config || params.create_blockers {
+            let protect_result =
+                apply_protection_with_options(&app, params.lock_config, params.create_blockers, true);
+            all_logs.extend(protect_result.logs.into_iter().map(|line| {
+                if line.starts_with("[DRY RUN]") {
+                    line
+                } else {
+                    format!("[DRY RUN] {}", line)
+                }
+            }));
+        } else {
+            all_logs.push("[DRY RUN] Skipping protection (all options disabled)".to_string());
+        }
+
+        progress::emit(&app, "done", "[DRY RUN] Preview complete", ProgressLevel::Info, 100);
+
+        return ProtectionResult {
+            success: true,
+            error: None,
+            logs: all_logs,
+        };
+    }
+
     // Check if CapCut is running
+    progress::emit(&app, "precheck", "Checking system state...", ProgressLevel::Info, 0);
     all_logs.push("Checking system state...".to_string());
     if process::is_capcut_running() {
+        let message = "CapCut is still running. Please close it.";
+        progress::emit(&app, "precheck", message, ProgressLevel::Error, 0);
         return ProtectionResult {
             success: false,
-            error: Some("CapCut is still running. Please close it.".to_string()),
+            error: Some(message.to_string()),
             logs: all_logs,
         };
     }
+    progress::emit(&app, "precheck", "[OK] No running instances", ProgressLevel::Info, 5);
     all_logs.push("[OK] No running instances".to_string());
 
+    let capcut_root = match scanner::get_capcut_root_path() {
+        Some(p) => p,
+        None => {
+            return ProtectionResult {
+                success: false,
+                error: Some("Failed to get LOCALAPPDATA".to_string()),
+                logs: all_logs,
+            }
+        }
+    };
+
+    // `txn` is dropped (and rolled back) on every early return below unless
+    // `txn.commit()` is reached at the end
+    let mut txn = match Transaction::new(&capcut_root) {
+        Ok(t) => t,
+        Err(e) => {
+            return ProtectionResult {
+                success: false,
+                error: Some(e),
+                logs: all_logs,
+            }
+        }
+    };
+
     // Delete versions
-    let delete_result = delete_versions(params.versions_to_delete);
-    all_logs.extend(delete_result.logs);
-    if !delete_result.success {
-        return ProtectionResult {
-            success: false,
-            error: delete_result.error,
-            logs: all_logs,
-        };
+    match quarantine_versions(&app, &mut txn, &params.versions_to_delete, 5, 40) {
+        Ok(logs) => all_logs.extend(logs),
+        Err((error, logs)) => {
+            all_logs.extend(logs);
+            return ProtectionResult {
+                success: false,
+                error: Some(error),
+                logs: all_logs,
+            };
+        }
     }
 
-    // Clean cache if enabled
+    // Clean cache if enabled. Quarantined into the same `txn` as the version
+    // deletes above, so a later step failing rolls the clean back too.
     if params.clean_cache {
+        progress::emit(&app, "clean_cache", "Cleaning cache directories...", ProgressLevel::Info, 40);
         all_logs.push("Cleaning cache directories...".to_string());
-        let cache_result = cleaner::clean_cache();
-        all_logs.extend(cache_result.logs);
+        match cleaner::quarantine_cache(&app, &mut txn, 40, 60) {
+            Ok(logs) => all_logs.extend(logs),
+            Err((error, logs)) => {
+                all_logs.extend(logs);
+                return ProtectionResult {
+                    success: false,
+                    error: Some(error),
+                    logs: all_logs,
+                };
+            }
+        }
     } else {
         all_logs.push("Skipping cache cleaning (disabled)".to_string());
     }
 
     // Apply protection (conditionally based on flags)
     if params.lock_config || params.create_blockers {
-        let protect_result = apply_protection_with_options(params.lock_config, params.create_blockers);
+        let protect_result = apply_protection_with_options(
+            &app,
+            params.lock_config,
+            params.create_blockers,
+            false,
+        );
         all_logs.extend(protect_result.logs);
         if !protect_result.success {
             return ProtectionResult {
@@ -288,6 +572,9 @@ pub fn run_full_protection(params: ProtectionParams) -> ProtectionResult {
         all_logs.push("Skipping protection (all options disabled)".to_string());
     }
 
+    txn.commit();
+    progress::emit(&app, "done", "[OK] Full protection applied", ProgressLevel::Info, 100);
+
     ProtectionResult {
         success: true,
         error: None,
@@ -301,62 +588,59 @@ pub struct ProtectionStatus {
     pub is_protected: bool,
     pub config_locked: bool,
     pub blockers_exist: bool,
+    /// `false` when no lock manifest could be found at all, in which case
+    /// every other field is `false` and `entries` is empty
+    pub manifest_found: bool,
+    /// `true` when the manifest exists but a recorded file is missing, its
+    /// hash no longer matches what was recorded at lock time, or its
+    /// recorded readonly attribute was cleared
+    pub tampered: bool,
+    pub entries: Vec<manifest::IntegrityVerdict>,
 }
 
-/// Check if protection is currently applied
+/// Check if protection is currently applied, verifying every file recorded
+/// in the lock manifest against its hash at lock time
 #[tauri::command]
 pub fn check_protection_status() -> ProtectionStatus {
-    let apps_path = match std::env::var("LOCALAPPDATA") {
-        Ok(p) => PathBuf::from(p).join("CapCut").join("Apps"),
-        Err(_) => return ProtectionStatus {
-            is_protected: false,
-            config_locked: false,
-            blockers_exist: false,
-        },
+    let empty_status = ProtectionStatus {
+        is_protected: false,
+        config_locked: false,
+        blockers_exist: false,
+        manifest_found: false,
+        tampered: false,
+        entries: vec![],
     };
 
-    let capcut_root = apps_path.parent().unwrap_or(&apps_path).to_path_buf();
-
-    // Check if ProductInfo.xml is a readonly empty file (blocker)
-    let product_info = apps_path.join("ProductInfo.xml");
-    let blockers_exist = if product_info.exists() {
-        if let Ok(meta) = fs::metadata(&product_info) {
-            meta.len() == 0 && meta.permissions().readonly()
-        } else {
-            false
-        }
-    } else {
-        false
+    let capcut_root = match scanner::get_capcut_root_path() {
+        Some(p) => p,
+        None => return empty_status,
     };
 
-    // Check if update.exe blocker exists
-    let update_blocker = capcut_root.join("User Data").join("Download").join("update.exe");
-    let update_blocked = if update_blocker.exists() {
-        if let Ok(meta) = fs::metadata(&update_blocker) {
-            meta.len() == 0 && meta.permissions().readonly()
-        } else {
-            false
-        }
-    } else {
-        false
+    let manifest = match manifest::Manifest::load(&capcut_root) {
+        Some(m) => m,
+        None => return empty_status,
     };
 
-    // Check if configure.ini has last_version=1.0.0.0
-    let config_path = apps_path.join("configure.ini");
-    let config_locked = if config_path.exists() {
-        if let Ok(content) = fs::read_to_string(&config_path) {
-            content.contains("last_version=1.0.0.0")
-        } else {
-            false
-        }
-    } else {
-        false
-    };
+    let blocker_verdicts: Vec<manifest::IntegrityVerdict> =
+        manifest.blockers.iter().map(|e| e.verify()).collect();
+    let config_verdict = manifest.config.as_ref().map(|e| e.verify());
+
+    let blockers_exist = !blocker_verdicts.is_empty() && blocker_verdicts.iter().all(|v| v.exists);
+    let config_locked = config_verdict.as_ref().map(|v| v.exists).unwrap_or(false);
+
+    let tampered = blocker_verdicts.iter().any(|v| v.tampered)
+        || config_verdict.as_ref().map(|v| v.tampered).unwrap_or(false);
+
+    let mut entries = blocker_verdicts;
+    entries.extend(config_verdict);
 
     ProtectionStatus {
-        is_protected: blockers_exist || update_blocked || config_locked,
+        is_protected: (blockers_exist || config_locked) && !tampered,
         config_locked,
-        blockers_exist: blockers_exist || update_blocked,
+        blockers_exist,
+        manifest_found: true,
+        tampered,
+        entries,
     }
 }
 
@@ -377,6 +661,15 @@ pub fn remove_protection() -> ProtectionResult {
     let capcut_root = apps_path.parent().unwrap_or(&apps_path).to_path_buf();
     let mut logs: Vec<String> = Vec::new();
 
+    // Remove the lock manifest first, before touching any of the files it
+    // records. The background watcher treats a manifest-recorded blocker or
+    // config change as tampering and "repairs" it; deleting the manifest up
+    // front makes it a no-op (`Manifest::load` returns `None`) instead of
+    // racing the teardown below and re-applying a blocker we just removed.
+    if let Err(e) = manifest::Manifest::remove(&capcut_root) {
+        logs.push(format!("[!] Could not remove lock manifest: {}", e));
+    }
+
     // Remove ProductInfo.xml blocker
     let product_info = apps_path.join("ProductInfo.xml");
     if product_info.exists() {
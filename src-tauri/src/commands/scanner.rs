@@ -0,0 +1,88 @@
+//! Version discovery functionality
+//! Migrated from original eframe/egui main.rs
+
+use crate::commands::version::Version;
+use serde::Serialize;
+use std::fs;
+use std::path::PathBuf;
+
+/// Get the CapCut root install directory (`%LOCALAPPDATA%\CapCut`)
+pub fn get_capcut_root_path() -> Option<PathBuf> {
+    std::env::var("LOCALAPPDATA")
+        .ok()
+        .map(|p| PathBuf::from(p).join("CapCut"))
+}
+
+/// Get the CapCut Apps directory (`%LOCALAPPDATA%\CapCut\Apps`)
+pub fn get_capcut_apps_path() -> Option<PathBuf> {
+    get_capcut_root_path().map(|p| p.join("Apps"))
+}
+
+/// A discovered CapCut version directory
+#[derive(Serialize)]
+pub struct VersionEntry {
+    pub name: String,
+    pub path: String,
+    /// `None` when the folder name doesn't parse as a four-component version
+    pub version: Option<Version>,
+}
+
+impl VersionEntry {
+    fn from_dir(path: PathBuf) -> Option<VersionEntry> {
+        let name = path.file_name()?.to_str()?.to_string();
+        let version = Version::parse(&name);
+        Some(VersionEntry {
+            name,
+            path: path.to_string_lossy().to_string(),
+            version,
+        })
+    }
+}
+
+/// List the version directories under `dir`, sorted ascending. Folders whose
+/// name doesn't parse as a version sort before every parsed version rather
+/// than panicking or being dropped.
+fn scan_dir(dir: &PathBuf) -> Vec<VersionEntry> {
+    let mut versions: Vec<VersionEntry> = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.is_dir())
+            .filter_map(VersionEntry::from_dir)
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+
+    versions.sort_by(|a, b| a.version.cmp(&b.version));
+    versions
+}
+
+/// Scan the Apps directory for installed version folders
+#[tauri::command]
+pub fn scan_versions() -> Vec<VersionEntry> {
+    match get_capcut_apps_path() {
+        Some(apps_path) => scan_dir(&apps_path),
+        None => Vec::new(),
+    }
+}
+
+/// Scan an arbitrary archive directory for backed-up version folders
+#[tauri::command]
+pub fn get_archive_versions(archive_path: String) -> Vec<VersionEntry> {
+    scan_dir(&PathBuf::from(archive_path))
+}
+
+/// CapCut install paths surfaced to the frontend
+#[derive(Serialize)]
+pub struct CapCutPaths {
+    pub root_path: Option<String>,
+    pub apps_path: Option<String>,
+}
+
+#[tauri::command]
+pub fn get_capcut_paths() -> CapCutPaths {
+    CapCutPaths {
+        root_path: get_capcut_root_path().map(|p| p.to_string_lossy().to_string()),
+        apps_path: get_capcut_apps_path().map(|p| p.to_string_lossy().to_string()),
+    }
+}
@@ -1,6 +1,8 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::fs;
+use crate::commands::manifest::Manifest;
 use crate::commands::scanner::{get_capcut_apps_path, get_capcut_root_path};
+use crate::commands::version::Version;
 use serde::Serialize;
 
 #[derive(Serialize)]
@@ -8,6 +10,21 @@ pub struct SwitchResult {
     pub success: bool,
     pub message: String,
     pub logs: Vec<String>,
+    /// Set when the target version is newer than what's currently active,
+    /// which risks re-triggering CapCut's auto-updater
+    pub warning: Option<String>,
+}
+
+/// Read the version CapCut is actually pointed at out of `ProductInfo.xml`'s
+/// `<Version>` tag. `configure.ini`'s `last_version` isn't usable for this:
+/// once protection has been applied it's pinned to the `LOCKED_VERSION`
+/// sentinel rather than tracking what's really active, so comparing against
+/// it would flag every future switch as "switching up".
+fn read_active_version(root_path: &Path) -> Option<Version> {
+    let content = fs::read_to_string(root_path.join("Apps").join("ProductInfo.xml")).ok()?;
+    let start = content.find("<Version>")? + "<Version>".len();
+    let end = content[start..].find("</Version>")? + start;
+    Version::parse(content[start..end].trim())
 }
 
 #[tauri::command]
@@ -23,6 +40,7 @@ pub fn switch_version(target_path: String) -> SwitchResult {
             success: false,
             message: "Target version not found".to_string(),
             logs,
+            warning: None,
         };
     }
 
@@ -33,9 +51,30 @@ pub fn switch_version(target_path: String) -> SwitchResult {
 
     logs.push(format!("Detected version: {}", version_name));
 
+    // Warn if the target is newer than what's currently active, since that
+    // risks re-triggering CapCut's auto-updater
+    let mut warning = None;
+    if let Some(root_path) = get_capcut_root_path() {
+        if let (Some(target_version), Some(active_version)) =
+            (Version::parse(version_name), read_active_version(&root_path))
+        {
+            if target_version > active_version {
+                let message = format!(
+                    "Switching up from active v{} to v{} may re-trigger CapCut's auto-updater",
+                    active_version, target_version
+                );
+                logs.push(format!("[!] {}", message));
+                warning = Some(message);
+            }
+        }
+    }
+
+    let mut capcut_root: Option<PathBuf> = None;
+
     // 1. Update ProductInfo.xml to point to this version
     // This is how CapCut launcher knows which EXE to run
     if let Some(root_path) = get_capcut_root_path() {
+        capcut_root = Some(root_path.clone());
         let product_info_path = root_path.join("Apps").join("ProductInfo.xml");
         logs.push(format!("Updating ProductInfo at: {:?}", product_info_path));
 
@@ -95,9 +134,25 @@ pub fn switch_version(target_path: String) -> SwitchResult {
     // This function assumes we are dealing with standard folders, but we could add logic
     // to rename "_backup" folders back to normal if needed.
 
+    // A switch intentionally de-protects ProductInfo.xml/configure.ini (it
+    // just cleared their readonly bit and rewrote them), so a manifest from
+    // a prior `apply_protection` no longer describes reality. Clear it
+    // rather than re-hash it in place: re-hashing but leaving `readonly`
+    // pinned to `true` would report this as "tampered", and silently
+    // re-baselining `readonly: false` as the new expected state would make
+    // `check_protection_status` claim protection is intact when it isn't.
+    // Clearing also means the watcher's `Manifest::load` returns `None`, so
+    // it won't race this switch and "repair" it back to the old version.
+    if let Some(root_path) = &capcut_root {
+        if let Err(e) = Manifest::remove(root_path) {
+            logs.push(format!("[!] Could not clear lock manifest: {}", e));
+        }
+    }
+
     SwitchResult {
         success: true,
         message: format!("Successfully switched to v{}", version_name),
         logs,
+        warning,
     }
 }
@@ -0,0 +1,136 @@
+//! RAII-guarded filesystem transaction with quarantine and rollback
+//!
+//! Borrows the staged-move pattern cargo's installer uses for uninstalls:
+//! nothing is deleted outright. Each path is moved into a quarantine folder
+//! and the move is recorded, so a `Transaction` dropped without `commit()`
+//! (an early return, a later step failing) restores every quarantined path
+//! to where it came from.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Guards a batch of quarantined moves
+pub struct Transaction {
+    moves: Vec<(PathBuf, PathBuf)>,
+    quarantine_root: PathBuf,
+    committed: bool,
+}
+
+impl Transaction {
+    /// Start a new transaction rooted at
+    /// `%LOCALAPPDATA%\CapCut\.ccvg_quarantine\<timestamp>`
+    pub fn new(capcut_root: &Path) -> Result<Transaction, String> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| e.to_string())?
+            .as_secs();
+
+        let quarantine_root = capcut_root
+            .join(".ccvg_quarantine")
+            .join(timestamp.to_string());
+        fs::create_dir_all(&quarantine_root).map_err(|e| e.to_string())?;
+
+        Ok(Transaction {
+            moves: Vec::new(),
+            quarantine_root,
+            committed: false,
+        })
+    }
+
+    /// Move `path` into quarantine instead of deleting it, recording the move
+    /// so it can be rolled back
+    pub fn quarantine(&mut self, path: &Path) -> Result<(), String> {
+        let name = path.file_name().ok_or("Invalid path")?;
+        let quarantine_path = self.quarantine_root.join(name);
+
+        fs::rename(path, &quarantine_path).map_err(|e| e.to_string())?;
+        self.moves.push((path.to_path_buf(), quarantine_path));
+        Ok(())
+    }
+
+    /// Finalize the transaction: clears the rollback list and purges the
+    /// quarantine directory, permanently discarding the quarantined data
+    pub fn commit(mut self) {
+        self.committed = true;
+        self.moves.clear();
+        let _ = fs::remove_dir_all(&self.quarantine_root);
+    }
+}
+
+impl Drop for Transaction {
+    fn drop(&mut self) {
+        if self.committed {
+            return;
+        }
+
+        for (original_path, quarantine_path) in self.moves.drain(..) {
+            if let Some(parent) = original_path.parent() {
+                let _ = fs::create_dir_all(parent);
+            }
+            let _ = fs::rename(&quarantine_path, &original_path);
+        }
+
+        let _ = fs::remove_dir_all(&self.quarantine_root);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// A fresh scratch directory under the OS temp dir, unique per test
+    fn scratch_dir(name: &str) -> PathBuf {
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let id = COUNTER.fetch_add(1, Ordering::SeqCst);
+        let dir = std::env::temp_dir().join(format!(
+            "ccvg_test_{}_{}_{}",
+            name,
+            std::process::id(),
+            id
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn drop_without_commit_restores_quarantined_paths() {
+        let root = scratch_dir("txn_rollback");
+        let target = root.join("version_a");
+        fs::create_dir_all(&target).unwrap();
+        fs::write(target.join("marker.txt"), "original").unwrap();
+
+        {
+            let mut txn = Transaction::new(&root).unwrap();
+            txn.quarantine(&target).unwrap();
+            assert!(!target.exists());
+            // txn dropped here without calling commit()
+        }
+
+        assert!(target.exists());
+        assert_eq!(
+            fs::read_to_string(target.join("marker.txt")).unwrap(),
+            "original"
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn commit_discards_quarantined_paths() {
+        let root = scratch_dir("txn_commit");
+        let target = root.join("version_b");
+        fs::create_dir_all(&target).unwrap();
+
+        let mut txn = Transaction::new(&root).unwrap();
+        let quarantine_root = txn.quarantine_root.clone();
+        txn.quarantine(&target).unwrap();
+        txn.commit();
+
+        assert!(!target.exists());
+        assert!(!quarantine_root.exists());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
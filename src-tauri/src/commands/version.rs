@@ -0,0 +1,88 @@
+//! Parsing and ordering for CapCut's four-component version scheme
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+/// A parsed CapCut version, e.g. `3.1.0.100`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct Version(pub u64, pub u64, pub u64, pub u64);
+
+impl Version {
+    /// Parse a version folder name into its four numeric components.
+    ///
+    /// Missing trailing components are padded with `0` (`"1.0"` becomes
+    /// `1.0.0.0`), and any components beyond the first four are ignored.
+    /// Returns `None` if one of the first four segments isn't purely numeric.
+    pub fn parse(name: &str) -> Option<Version> {
+        let mut segments = name.split('.');
+        let mut parts = [0u64; 4];
+
+        for part in parts.iter_mut() {
+            match segments.next() {
+                Some(segment) => {
+                    if !segment.bytes().all(|b| b.is_ascii_digit()) {
+                        return None;
+                    }
+                    *part = segment.parse().ok()?
+                }
+                None => break,
+            }
+        }
+
+        Some(Version(parts[0], parts[1], parts[2], parts[3]))
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.0, self.1, self.2, self.3).cmp(&(other.0, other.1, other.2, other.3))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}.{}", self.0, self.1, self.2, self.3)
+    }
+}
+
+impl FromStr for Version {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Version::parse(s).ok_or(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pads_missing_trailing_components_with_zero() {
+        assert_eq!(Version::parse("1.0"), Some(Version(1, 0, 0, 0)));
+    }
+
+    #[test]
+    fn truncates_components_beyond_four() {
+        assert_eq!(Version::parse("3.1.0.100.200"), Some(Version(3, 1, 0, 100)));
+    }
+
+    #[test]
+    fn rejects_non_numeric_segment() {
+        assert_eq!(Version::parse("3.1.0.abc"), None);
+    }
+
+    #[test]
+    fn orders_lexicographically_by_component() {
+        assert!(Version(1, 2, 0, 0) < Version(1, 10, 0, 0));
+        assert!(Version(3, 1, 0, 100) > Version(3, 1, 0, 99));
+        assert_eq!(Version(1, 0, 0, 0), Version(1, 0, 0, 0));
+    }
+}
@@ -0,0 +1,198 @@
+//! Background watcher that re-applies blockers when CapCut's updater removes
+//! them
+//!
+//! Watches the Apps folder and the `User Data\Download` folder for changes,
+//! debouncing a burst of filesystem events into a single repair pass, and
+//! persists whether watching is enabled so it can auto-start on launch.
+
+use crate::commands::manifest::Manifest;
+use crate::commands::protector;
+use crate::commands::scanner;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+/// Event emitted when the watcher detects and repairs a removed blocker
+pub const ALERT_EVENT: &str = "ccvg://watcher-alert";
+
+/// How long to wait after the last filesystem event before repairing, so a
+/// burst of writes from CapCut's updater collapses into one repair pass
+const DEBOUNCE: Duration = Duration::from_millis(800);
+
+/// Name of the preference file recording whether the watcher is enabled
+const PREFERENCE_FILE: &str = ".ccvg_watch_enabled";
+
+#[derive(Serialize, Clone)]
+pub struct WatcherAlert {
+    pub path: String,
+    pub message: String,
+}
+
+static WATCHER_RUNNING: AtomicBool = AtomicBool::new(false);
+static WATCHER_HANDLE: Mutex<Option<RecommendedWatcher>> = Mutex::new(None);
+
+/// Bumped on every `start_watcher`/`stop_watcher` call. Each spawned debounce
+/// thread captures the generation it was started with and checks it against
+/// the current one instead of only the shared `WATCHER_RUNNING` flag, so a
+/// thread from a prior `start_watcher` call can't be mistaken for the
+/// current one if the flag flips back to `true` before that old thread
+/// notices it should have stopped.
+static WATCHER_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Whether the watcher is currently running
+#[tauri::command]
+pub fn is_watcher_running() -> bool {
+    WATCHER_RUNNING.load(Ordering::SeqCst)
+}
+
+/// Start the background watcher, persisting the "watch enabled" preference
+/// so it auto-starts on the next launch
+#[tauri::command]
+pub fn start_watcher(app: AppHandle) -> Result<(), String> {
+    if WATCHER_RUNNING.swap(true, Ordering::SeqCst) {
+        return Ok(());
+    }
+
+    set_watch_preference(true)?;
+
+    let generation = WATCHER_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    if let Err(e) = spawn_watcher(app, generation) {
+        WATCHER_RUNNING.store(false, Ordering::SeqCst);
+        return Err(e);
+    }
+
+    Ok(())
+}
+
+/// Stop the background watcher and persist the disabled preference
+#[tauri::command]
+pub fn stop_watcher() -> Result<(), String> {
+    WATCHER_RUNNING.store(false, Ordering::SeqCst);
+    WATCHER_GENERATION.fetch_add(1, Ordering::SeqCst);
+    *WATCHER_HANDLE.lock().unwrap() = None;
+    set_watch_preference(false)
+}
+
+/// Re-start the watcher automatically if it was left enabled on a prior run;
+/// called once from the Tauri setup hook
+pub fn autostart_if_enabled(app: &AppHandle) {
+    if watch_preference() {
+        let _ = start_watcher(app.clone());
+    }
+}
+
+fn preference_path() -> Option<PathBuf> {
+    scanner::get_capcut_root_path().map(|p| p.join(PREFERENCE_FILE))
+}
+
+fn set_watch_preference(enabled: bool) -> Result<(), String> {
+    let path = preference_path().ok_or("Failed to get LOCALAPPDATA")?;
+    if enabled {
+        std::fs::write(path, "1").map_err(|e| e.to_string())
+    } else if path.exists() {
+        std::fs::remove_file(path).map_err(|e| e.to_string())
+    } else {
+        Ok(())
+    }
+}
+
+fn watch_preference() -> bool {
+    preference_path().map(|p| p.exists()).unwrap_or(false)
+}
+
+fn spawn_watcher(app: AppHandle, generation: u64) -> Result<(), String> {
+    let apps_path = scanner::get_capcut_apps_path().ok_or("Failed to get LOCALAPPDATA")?;
+    let capcut_root = scanner::get_capcut_root_path().ok_or("Failed to get LOCALAPPDATA")?;
+    let download_path = capcut_root.join("User Data").join("Download");
+
+    let (tx, rx) = channel();
+    let mut watcher: RecommendedWatcher = notify::recommended_watcher(move |res| {
+        let _ = tx.send(res);
+    })
+    .map_err(|e| e.to_string())?;
+
+    if apps_path.exists() {
+        watcher
+            .watch(&apps_path, RecursiveMode::NonRecursive)
+            .map_err(|e| e.to_string())?;
+    }
+    if download_path.exists() {
+        watcher
+            .watch(&download_path, RecursiveMode::NonRecursive)
+            .map_err(|e| e.to_string())?;
+    }
+
+    *WATCHER_HANDLE.lock().unwrap() = Some(watcher);
+
+    std::thread::spawn(move || {
+        while WATCHER_RUNNING.load(Ordering::SeqCst)
+            && WATCHER_GENERATION.load(Ordering::SeqCst) == generation
+        {
+            match rx.recv_timeout(DEBOUNCE) {
+                Ok(_) => {
+                    // Drain any further events inside the debounce window so
+                    // a burst of updater writes collapses into a single
+                    // repair pass
+                    while rx.recv_timeout(DEBOUNCE).is_ok() {}
+                    check_and_repair(&app, &capcut_root, &apps_path);
+                }
+                Err(RecvTimeoutError::Timeout) => continue,
+                // The sender lives inside the `RecommendedWatcher` stored in
+                // `WATCHER_HANDLE`; a disconnect means `stop_watcher` already
+                // dropped it, so this thread is done regardless of whether a
+                // newer `start_watcher` has since flipped `WATCHER_RUNNING`
+                // back to `true`. Looping here would busy-spin forever.
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Re-hash every file recorded in the lock manifest; repair and alert on
+/// anything that was removed or modified
+fn check_and_repair(app: &AppHandle, capcut_root: &Path, apps_path: &Path) {
+    let manifest = match Manifest::load(capcut_root) {
+        Some(m) => m,
+        None => return,
+    };
+
+    for blocker in &manifest.blockers {
+        if !blocker.verify().tampered {
+            continue;
+        }
+
+        alert(app, &blocker.path, "Blocker was removed or modified, re-applying");
+
+        if let Err(e) = protector::reapply_blocker(&PathBuf::from(&blocker.path)) {
+            alert(app, &blocker.path, &format!("Failed to re-apply blocker: {}", e));
+        }
+    }
+
+    if let Some(config) = &manifest.config {
+        if config.verify().tampered {
+            alert(app, &config.path, "Configuration was modified, re-locking");
+
+            if let Err(e) = protector::reapply_config_lock(apps_path) {
+                alert(app, &config.path, &format!("Failed to re-lock configuration: {}", e));
+            }
+        }
+    }
+}
+
+fn alert(app: &AppHandle, path: &str, message: &str) {
+    log::warn!("[watcher] {}: {}", path, message);
+    let _ = app.emit(
+        ALERT_EVENT,
+        WatcherAlert {
+            path: path.to_string(),
+            message: message.to_string(),
+        },
+    );
+}
@@ -3,12 +3,18 @@
 
 mod commands;
 
-use commands::{cleaner, process, protector, scanner, switcher};
+use commands::{cleaner, process, protector, scanner, switcher, watcher};
+use tauri::Manager;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_log::Builder::new().build())
+        .setup(|app| {
+            watcher::autostart_if_enabled(&app.handle());
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             // Scanner commands
             scanner::get_archive_versions,
@@ -28,6 +34,10 @@ pub fn run() {
             protector::remove_protection,
             // Switcher commands
             switcher::switch_version,
+            // Watcher commands
+            watcher::start_watcher,
+            watcher::stop_watcher,
+            watcher::is_watcher_running,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");